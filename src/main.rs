@@ -1,5 +1,7 @@
 use reqwest::blocking::Client;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use std::{fs, thread};
 
@@ -7,62 +9,526 @@ const MANIFEST_URL: &str = "https://piston-meta.mojang.com/mc/game/version_manif
 const MINECRAFT_ICON_URL: &str =
     "https://resources.download.minecraft.net/df/df274fe57c49ef1af6d218703d805db76a5c8af9";
 
-const CACHE_FILE: &str = "prev_mc_snapshot.txt";
-const UPDATE_INTERVAL: Duration = Duration::from_secs(3 * 60);
+const LEGACY_CACHE_FILE: &str = "prev_mc_snapshot.txt";
+const STATE_FILE: &str = "mcupdate_state.json";
 
-const NTFY_HOST: &str = "https://ntfy.sh/";
-const NTFY_TOPIC: &str = "mcupdate";
-const NTFY_ICON: &str = MINECRAFT_ICON_URL;
+const CONFIG_PATH_ENV: &str = "MCUPDATE_CONFIG_PATH";
+const DEFAULT_CONFIG_PATH: &str = "mcupdate.toml";
 
-const DC_WEBHOOK_NAME: &str = "Minecraft Update";
-const DC_WEBHOOK_ICON: &str = MINECRAFT_ICON_URL;
+const TELEGRAM_API_URL: &str = "https://api.telegram.org";
 
-fn fetch_json(client: &Client, url: &str) -> Option<Value> {
-    if let Ok(response) = client.get(url).send() {
-        if let Ok(text) = response.text() {
-            return serde_json::from_str(&text).ok();
+fn default_update_interval_secs() -> u64 {
+    3 * 60
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// `[ntfy]` block of [`Config`], see https://ntfy.sh for the topic/priority semantics.
+#[derive(Deserialize)]
+struct NtfyConfig {
+    #[serde(default = "default_true")]
+    enabled: bool,
+    #[serde(default = "NtfyConfig::default_host")]
+    host: String,
+    #[serde(default = "NtfyConfig::default_topic")]
+    topic: String,
+    #[serde(default = "NtfyConfig::default_priority")]
+    priority: u8,
+    #[serde(default = "NtfyConfig::default_icon")]
+    icon: String,
+}
+
+impl NtfyConfig {
+    fn default_host() -> String {
+        "https://ntfy.sh/".to_string()
+    }
+    fn default_topic() -> String {
+        "mcupdate".to_string()
+    }
+    fn default_priority() -> u8 {
+        4
+    }
+    fn default_icon() -> String {
+        MINECRAFT_ICON_URL.to_string()
+    }
+}
+
+impl Default for NtfyConfig {
+    fn default() -> Self {
+        NtfyConfig {
+            enabled: default_true(),
+            host: NtfyConfig::default_host(),
+            topic: NtfyConfig::default_topic(),
+            priority: NtfyConfig::default_priority(),
+            icon: NtfyConfig::default_icon(),
         }
     }
-    None
 }
 
-fn check_minecraft_update(
-    client: &Client,
-    discord_webhook_url: &str,
-    healthchecks_url: &Option<String>,
-) {
-    if let Some(healthchecks_url) = healthchecks_url {
-        client.get(healthchecks_url).send().unwrap();
+/// `[discord]` block of [`Config`]. `enabled` is left unset by default so it
+/// falls back to whether `webhook_url` is configured; an explicit `true`
+/// with no `webhook_url` is a misconfiguration, see [`Config::validate`].
+#[derive(Deserialize)]
+struct DiscordConfig {
+    #[serde(default)]
+    enabled: Option<bool>,
+    #[serde(default)]
+    webhook_url: Option<String>,
+    #[serde(default = "DiscordConfig::default_username")]
+    username: String,
+    #[serde(default = "DiscordConfig::default_icon")]
+    icon: String,
+}
+
+impl DiscordConfig {
+    fn default_username() -> String {
+        "Minecraft Update".to_string()
+    }
+    fn default_icon() -> String {
+        MINECRAFT_ICON_URL.to_string()
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or_else(|| self.webhook_url.is_some())
+    }
+}
+
+impl Default for DiscordConfig {
+    fn default() -> Self {
+        DiscordConfig {
+            enabled: None,
+            webhook_url: None,
+            username: DiscordConfig::default_username(),
+            icon: DiscordConfig::default_icon(),
+        }
+    }
+}
+
+/// `[telegram]` block of [`Config`]. `enabled` is left unset by default so it
+/// falls back to whether `bot_token`/`chat_id` are both configured; an
+/// explicit `true` with either missing is a misconfiguration, see
+/// [`Config::validate`].
+#[derive(Deserialize, Default)]
+struct TelegramConfig {
+    #[serde(default)]
+    enabled: Option<bool>,
+    #[serde(default)]
+    bot_token: Option<String>,
+    #[serde(default)]
+    chat_id: Option<String>,
+}
+
+impl TelegramConfig {
+    fn is_enabled(&self) -> bool {
+        self.enabled
+            .unwrap_or_else(|| self.bot_token.is_some() && self.chat_id.is_some())
+    }
+}
+
+/// Top-level `mcupdate.toml` shape, loaded once in `main` via [`Config::load`].
+/// The path can be overridden with the `MCUPDATE_CONFIG_PATH` env var.
+#[derive(Deserialize)]
+struct Config {
+    #[serde(default = "default_update_interval_secs")]
+    update_interval_secs: u64,
+    #[serde(default)]
+    ntfy: NtfyConfig,
+    #[serde(default)]
+    discord: DiscordConfig,
+    #[serde(default)]
+    telegram: TelegramConfig,
+    #[serde(default)]
+    healthchecks_url: Option<String>,
+}
+
+impl Config {
+    fn load() -> Config {
+        let explicit_path = std::env::var(CONFIG_PATH_ENV).ok();
+        let path = explicit_path.clone().unwrap_or_else(|| DEFAULT_CONFIG_PATH.to_string());
+        let config = match fs::read_to_string(&path) {
+            Ok(text) => toml::from_str(&text).unwrap_or_else(|err| panic!("invalid {}: {}", path, err)),
+            Err(err) if explicit_path.is_some() => {
+                panic!("{}={} but the file could not be read: {}", CONFIG_PATH_ENV, path, err);
+            }
+            Err(_) => {
+                println!("No {} found, falling back to defaults", path);
+                toml::from_str("").unwrap()
+            }
+        };
+        Config::validate(config)
+    }
+
+    fn validate(self) -> Config {
+        if self.discord.enabled == Some(true) && self.discord.webhook_url.is_none() {
+            panic!("discord.enabled is true but discord.webhook_url is not set");
+        }
+        if self.telegram.enabled == Some(true)
+            && (self.telegram.bot_token.is_none() || self.telegram.chat_id.is_none())
+        {
+            panic!("telegram.enabled is true but bot_token/chat_id are not both set");
+        }
+        self
+    }
+
+    fn log_active_sinks(&self) {
+        println!(
+            "Active sinks: ntfy={} discord={} telegram={}",
+            self.ntfy.enabled,
+            self.discord.is_enabled(),
+            self.telegram.is_enabled(),
+        );
+    }
+}
+
+/// Last announced version id per stream, namespaced by [`VersionSource::namespace`]
+/// so each source keeps its own slice of [`STATE_FILE`].
+type SourceState = HashMap<String, String>;
+
+/// On-disk shape of [`STATE_FILE`]: per-namespace last-announced ids, plus the
+/// unix epoch the state was last saved (carried forward from chunk0-3's
+/// `last_seen` field, which the multi-source refactor must not drop).
+#[derive(Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct StateFile {
+    #[serde(default)]
+    sources: HashMap<String, SourceState>,
+    #[serde(default)]
+    last_seen: u64,
+}
+
+/// The chunk0-6-but-pre-`last_seen` on-disk shape: a flat multi-source map
+/// with no top-level fields, kept around only to migrate state files written
+/// before this field was restored.
+type FlatStateFile = HashMap<String, SourceState>;
+
+/// The pre-chunk0-6 on-disk shape, kept around only to migrate old state files
+/// that predate the multi-source cache namespacing.
+#[derive(Deserialize)]
+struct LegacyMcState {
+    #[serde(default)]
+    release: String,
+    #[serde(default)]
+    snapshot: String,
+    #[serde(default)]
+    last_seen: u64,
+}
+
+fn load_state() -> StateFile {
+    if let Ok(text) = fs::read_to_string(STATE_FILE) {
+        if let Ok(state) = serde_json::from_str::<StateFile>(&text) {
+            return state;
+        }
+        if let Ok(sources) = serde_json::from_str::<FlatStateFile>(&text) {
+            println!("Migrating {} into the last_seen-tracking schema", STATE_FILE);
+            return StateFile { sources, last_seen: 0 };
+        }
+        if let Ok(legacy) = serde_json::from_str::<LegacyMcState>(&text) {
+            println!("Migrating {} into the mojang source namespace", STATE_FILE);
+            return StateFile {
+                sources: HashMap::from([("mojang".to_string(), SourceState::from([
+                    ("release".to_string(), legacy.release),
+                    ("snapshot".to_string(), legacy.snapshot),
+                ]))]),
+                last_seen: legacy.last_seen,
+            };
+        }
+        println!("Ignoring unreadable {}, starting from a blank state", STATE_FILE);
+    }
+
+    // migrate the original snapshot-only cache file so an existing install
+    // doesn't re-announce the snapshot it already notified about
+    if let Ok(snapshot) = fs::read_to_string(LEGACY_CACHE_FILE) {
+        println!("Migrating {} into {}", LEGACY_CACHE_FILE, STATE_FILE);
+        return StateFile {
+            sources: HashMap::from([(
+                "mojang".to_string(),
+                SourceState::from([("snapshot".to_string(), snapshot)]),
+            )]),
+            last_seen: 0,
+        };
+    }
+
+    StateFile::default()
+}
+
+fn save_state(state: &StateFile) {
+    match serde_json::to_string_pretty(state) {
+        Ok(text) => {
+            if let Err(err) = fs::write(STATE_FILE, text) {
+                println!("Failed to write {}: {}", STATE_FILE, err);
+            }
+        }
+        Err(err) => println!("Failed to serialize state: {}", err),
+    }
+}
+
+const RETRY_BASE_MS_DEFAULT: u64 = 500;
+const RETRY_CAP_MS_DEFAULT: u64 = 30_000;
+const RETRY_MAX_ATTEMPTS_DEFAULT: u32 = 5;
+
+/// Exponential backoff parameters for [`fetch_with_retry`], overridable via
+/// `FETCH_RETRY_BASE_MS`, `FETCH_RETRY_CAP_MS` and `FETCH_RETRY_MAX_ATTEMPTS`.
+struct RetryPolicy {
+    base: Duration,
+    cap: Duration,
+    max_attempts: u32,
+}
+
+impl RetryPolicy {
+    fn from_env() -> RetryPolicy {
+        fn env_or<T: std::str::FromStr>(key: &str, default: T) -> T {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default)
+        }
+
+        RetryPolicy {
+            base: Duration::from_millis(env_or("FETCH_RETRY_BASE_MS", RETRY_BASE_MS_DEFAULT)),
+            cap: Duration::from_millis(env_or("FETCH_RETRY_CAP_MS", RETRY_CAP_MS_DEFAULT)),
+            max_attempts: env_or("FETCH_RETRY_MAX_ATTEMPTS", RETRY_MAX_ATTEMPTS_DEFAULT),
+        }
+    }
+
+    /// Delay after failed attempt `attempt` (1-indexed) before the next one,
+    /// `min(base * 2^attempt, cap)` plus a random fraction of `base` to avoid
+    /// thundering-herd re-polls.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exp = self.base.saturating_mul(1 << attempt.min(16)).min(self.cap);
+        exp.saturating_add(jitter(self.base))
+    }
+}
+
+/// Cheap dependency-free jitter source seeded off the wall clock; doesn't need
+/// to be cryptographically random, just spread re-polls apart.
+fn jitter(base: Duration) -> Duration {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    base.mul_f64((nanos % 1000) as f64 / 1000.0)
+}
+
+#[derive(Debug)]
+enum FetchError {
+    Request(reqwest::Error),
+    Status(reqwest::StatusCode),
+    Decode(serde_json::Error),
+    RetriesExhausted,
+}
+
+impl std::fmt::Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::Request(err) => write!(f, "request failed: {}", err),
+            FetchError::Status(status) => write!(f, "unexpected status: {}", status),
+            FetchError::Decode(err) => write!(f, "invalid JSON body: {}", err),
+            FetchError::RetriesExhausted => write!(f, "retries exhausted"),
+        }
+    }
+}
+
+impl FetchError {
+    fn is_retryable(&self) -> bool {
+        match self {
+            FetchError::Request(err) => err.is_timeout() || err.is_connect(),
+            FetchError::Status(status) => status.is_server_error(),
+            FetchError::Decode(_) => true,
+            FetchError::RetriesExhausted => false,
+        }
     }
+}
 
-    let manifest_json = fetch_json(client, &MANIFEST_URL);
-    if manifest_json.is_none() {
-        println!("Received invalid response while requesting manifest url");
-        return;
+fn try_fetch_json(client: &Client, url: &str) -> Result<Value, FetchError> {
+    let response = client.get(url).send().map_err(FetchError::Request)?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(FetchError::Status(status));
     }
-    let manifest_json = manifest_json.unwrap();
+    let text = response.text().map_err(FetchError::Request)?;
+    serde_json::from_str(&text).map_err(FetchError::Decode)
+}
 
-    let latest_snapshot = manifest_json["latest"]["snapshot"].as_str().unwrap();
-    if fs::exists(CACHE_FILE).unwrap_or(false) {
-        // if this has been executed before, check nothing changed
-        let prev_snapshot = fs::read_to_string(CACHE_FILE).unwrap();
-        if latest_snapshot == prev_snapshot {
-            println!("Previous snapshot is still latest snapshot: {}", prev_snapshot);
-            return;
+fn fetch_with_retry(client: &Client, url: &str, policy: &RetryPolicy) -> Result<Value, FetchError> {
+    let mut last_err = FetchError::RetriesExhausted;
+    for attempt in 1..=policy.max_attempts {
+        match try_fetch_json(client, url) {
+            Ok(value) => return Ok(value),
+            Err(err) if attempt < policy.max_attempts && err.is_retryable() => {
+                let delay = policy.backoff(attempt);
+                println!(
+                    "Attempt {}/{} for {} failed ({}), retrying in {:?}",
+                    attempt, policy.max_attempts, url, err, delay,
+                );
+                thread::sleep(delay);
+                last_err = err;
+            }
+            Err(err) => return Err(err),
         }
     }
+    Err(last_err)
+}
+
+fn try_send(builder: reqwest::blocking::RequestBuilder) -> Result<reqwest::blocking::Response, FetchError> {
+    let response = builder.send().map_err(FetchError::Request)?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(FetchError::Status(status));
+    }
+    Ok(response)
+}
+
+/// Same retry/backoff policy as [`fetch_with_retry`], but for POSTing a
+/// notification instead of fetching JSON. `build_request` is called fresh on
+/// every attempt since a sent [`reqwest::blocking::RequestBuilder`] is consumed.
+fn send_with_retry(
+    label: &str,
+    policy: &RetryPolicy,
+    build_request: impl Fn() -> reqwest::blocking::RequestBuilder,
+) -> Result<reqwest::blocking::Response, FetchError> {
+    let mut last_err = FetchError::RetriesExhausted;
+    for attempt in 1..=policy.max_attempts {
+        match try_send(build_request()) {
+            Ok(response) => return Ok(response),
+            Err(err) if attempt < policy.max_attempts && err.is_retryable() => {
+                let delay = policy.backoff(attempt);
+                println!(
+                    "Attempt {}/{} to post to {} failed ({}), retrying in {:?}",
+                    attempt, policy.max_attempts, label, err, delay,
+                );
+                thread::sleep(delay);
+                last_err = err;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    Err(last_err)
+}
+
+/// A single stream update (a new release, snapshot, loader build, ...)
+/// normalized across every [`VersionSource`] so the notification layer never
+/// has to know which backend produced it.
+struct VersionEvent {
+    stream: String,
+    id: String,
+    kind: String,
+    release_time: String,
+    url: String,
+    java_major_version: Option<u64>,
+    server_jar_size: Option<u64>,
+    client_jar_url: Option<String>,
+    server_jar_url: Option<String>,
+}
+
+/// A pollable backend for watching version manifests (Mojang, Fabric, Paper,
+/// ...). Each source owns a namespace in [`STATE_FILE`] so they don't clobber
+/// each other's cached stream ids.
+trait VersionSource: Send + Sync {
+    fn namespace(&self) -> &str;
+
+    /// Compares the upstream manifest against `previous` (this source's slice
+    /// of the state file from the last cycle) and returns the events for
+    /// whichever streams changed, or `None` if the fetch failed outright.
+    fn poll(
+        &self,
+        client: &Client,
+        retry_policy: &RetryPolicy,
+        previous: &SourceState,
+    ) -> Option<Vec<VersionEvent>>;
+}
+
+struct MojangSource;
+
+impl VersionSource for MojangSource {
+    fn namespace(&self) -> &str {
+        "mojang"
+    }
 
-    let latest_data_url = manifest_json["versions"][0]["url"].as_str().unwrap();
-    let latest_data_json = fetch_json(client, latest_data_url).unwrap();
-    let release_time_str = latest_data_json["releaseTime"].as_str().unwrap();
-    let version_type = latest_data_json["type"].as_str().unwrap();
+    fn poll(
+        &self,
+        client: &Client,
+        retry_policy: &RetryPolicy,
+        previous: &SourceState,
+    ) -> Option<Vec<VersionEvent>> {
+        let manifest_json = match fetch_with_retry(client, MANIFEST_URL, retry_policy) {
+            Ok(json) => json,
+            Err(err) => {
+                println!("Giving up on manifest url for this cycle: {}", err);
+                return None;
+            }
+        };
 
-    println!(
-        "Encountered new Minecraft {} {} (released at {})",
-        version_type, latest_snapshot, release_time_str,
-    );
+        let latest_release = manifest_json["latest"]["release"].as_str()?;
+        let latest_snapshot = manifest_json["latest"]["snapshot"].as_str()?;
 
-    let release_time_secs = chrono::DateTime::parse_from_rfc3339(release_time_str)
+        let mut changed_streams = Vec::new();
+        if previous.get("release").map(String::as_str) != Some(latest_release) {
+            changed_streams.push(("release", latest_release));
+        }
+        if previous.get("snapshot").map(String::as_str) != Some(latest_snapshot) {
+            changed_streams.push(("snapshot", latest_snapshot));
+        }
+
+        if changed_streams.is_empty() {
+            return Some(Vec::new());
+        }
+
+        let mut events = Vec::new();
+        for (stream, id) in changed_streams {
+            let version_entry = manifest_json["versions"]
+                .as_array()
+                .and_then(|versions| versions.iter().find(|v| v["id"].as_str() == Some(id)));
+            let url = match version_entry.and_then(|v| v["url"].as_str()) {
+                Some(url) => url,
+                None => {
+                    println!("Could not find manifest entry for {} {}", stream, id);
+                    continue;
+                }
+            };
+
+            let version_json = match fetch_with_retry(client, url, retry_policy) {
+                Ok(json) => json,
+                Err(err) => {
+                    println!("Giving up on version url for this cycle: {}", err);
+                    continue;
+                }
+            };
+            let release_time = match version_json["releaseTime"].as_str() {
+                Some(time) => time.to_string(),
+                None => continue,
+            };
+            let kind = version_json["type"].as_str().unwrap_or(stream).to_string();
+
+            println!(
+                "Encountered new Minecraft {} {} {} (released at {})",
+                stream, kind, id, release_time,
+            );
+
+            events.push(VersionEvent {
+                stream: stream.to_string(),
+                id: id.to_string(),
+                kind,
+                release_time,
+                url: url.to_string(),
+                java_major_version: version_json["javaVersion"]["majorVersion"].as_u64(),
+                server_jar_size: version_json["downloads"]["server"]["size"].as_u64(),
+                client_jar_url: version_json["downloads"]["client"]["url"]
+                    .as_str()
+                    .map(str::to_string),
+                server_jar_url: version_json["downloads"]["server"]["url"]
+                    .as_str()
+                    .map(str::to_string),
+            });
+        }
+        Some(events)
+    }
+}
+
+/// `X hour(s) ago` relative to now, used for the "released" notification field.
+fn release_age_string(release_time: &str) -> String {
+    let release_time_secs = chrono::DateTime::parse_from_rfc3339(release_time)
         .ok()
         .unwrap()
         .timestamp();
@@ -71,67 +537,226 @@ fn check_minecraft_update(
         .ok()
         .unwrap()
         .as_secs() as i64;
-    let release_diff_hours = (current_time_secs - release_time_secs) / 60 / 60;
-    let release_diff_string = format!(
-        "{} hour{} ago",
-        release_diff_hours,
-        if release_diff_hours == 1 { "" } else { "s" },
-    );
-
-    let response = client
-        .post(NTFY_HOST)
-        .header("Icon", NTFY_ICON)
-        .json(&serde_json::json!({
-            "topic": NTFY_TOPIC,
-            "message": format!("{} {} {}", version_type, latest_snapshot, release_diff_string),
-            "title": format!("New Minecraft {}", version_type),
-            "tags": ["minecraft", "update", "snapshot"],
-            "priority": 4,
-            "click": latest_data_url,
-        }))
-        .send()
-        .unwrap();
-    println!(
-        "Posted to ntfy.sh, received code {}",
-        response.status().as_str(),
-    );
-
-    let response = client
-        .post(discord_webhook_url)
-        .header("Content-Type", "application/json")
-        .json(&serde_json::json!({
-            "embeds": [
-                {
-                    "title": format!("New Minecraft {}: {}", version_type, latest_snapshot),
-                    "timestamp": release_time_str,
-                    "color": 16776960,
-                    "footer": {
-                        "text": DC_WEBHOOK_NAME,
-                        "icon_url": DC_WEBHOOK_ICON
-                    }
-                }
-            ]
-        }))
-        .send()
-        .unwrap();
-    println!(
-        "Posted to Discord Webhook, received code {}",
-        response.status().as_str(),
-    );
+    let diff_hours = (current_time_secs - release_time_secs) / 60 / 60;
+    format!("{} hour{} ago", diff_hours, if diff_hours == 1 { "" } else { "s" })
+}
+
+fn poll_sources(
+    client: &Client,
+    config: &Config,
+    retry_policy: &RetryPolicy,
+    sources: &[Box<dyn VersionSource>],
+) {
+    if let Some(healthchecks_url) = &config.healthchecks_url {
+        if let Err(err) = client.get(healthchecks_url).send() {
+            println!("Failed to ping healthchecks url: {}", err);
+        }
+    }
+
+    let mut state = load_state();
+    for source in sources {
+        let previous = state.sources.entry(source.namespace().to_string()).or_default();
+        let events = match source.poll(client, retry_policy, previous) {
+            Some(events) => events,
+            None => continue,
+        };
+        if events.is_empty() {
+            println!("No new streams for source {}", source.namespace());
+            continue;
+        }
 
-    fs::write(CACHE_FILE, latest_snapshot).ok().unwrap();
+        for event in &events {
+            send_notifications(
+                client,
+                config,
+                retry_policy,
+                event,
+                &release_age_string(&event.release_time),
+            );
+            previous.insert(event.stream.clone(), event.id.clone());
+        }
+    }
+    state.last_seen = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    save_state(&state);
+}
+
+fn send_notifications(
+    client: &Client,
+    config: &Config,
+    retry_policy: &RetryPolicy,
+    event: &VersionEvent,
+    release_diff_string: &str,
+) {
+    if config.ntfy.enabled {
+        let result = send_with_retry("ntfy.sh", retry_policy, || {
+            client
+                .post(&config.ntfy.host)
+                .header("Icon", &config.ntfy.icon)
+                .json(&serde_json::json!({
+                    "topic": config.ntfy.topic,
+                    "message": format!("{} {} {} {}", event.stream, event.kind, event.id, release_diff_string),
+                    "title": format!("New Minecraft {}", event.stream),
+                    "tags": ["minecraft", "update", "snapshot"],
+                    "priority": config.ntfy.priority,
+                    "click": event.url,
+                }))
+        });
+        match result {
+            Ok(response) => println!(
+                "Posted to ntfy.sh, received code {}",
+                response.status().as_str(),
+            ),
+            Err(err) => println!("Giving up on posting to ntfy.sh this cycle: {}", err),
+        }
+    }
+
+    if config.discord.is_enabled() {
+        let webhook_url = config
+            .discord
+            .webhook_url
+            .as_ref()
+            .expect("DiscordConfig::is_enabled() only returns true when webhook_url is set");
+        let mut fields = Vec::new();
+        if let Some(major_version) = event.java_major_version {
+            fields.push(serde_json::json!({
+                "name": "Java Version",
+                "value": format!("{}", major_version),
+                "inline": true,
+            }));
+        }
+        if let Some(size) = event.server_jar_size {
+            fields.push(serde_json::json!({
+                "name": "Server Jar Size",
+                "value": format_bytes(size),
+                "inline": true,
+            }));
+        }
+        if let Some(url) = &event.client_jar_url {
+            fields.push(serde_json::json!({
+                "name": "Client Jar",
+                "value": format!("[Download]({})", url),
+                "inline": true,
+            }));
+        }
+        if let Some(url) = &event.server_jar_url {
+            fields.push(serde_json::json!({
+                "name": "Server Jar",
+                "value": format!("[Download]({})", url),
+                "inline": true,
+            }));
+        }
+        fields.push(serde_json::json!({
+            "name": "Released",
+            "value": release_diff_string,
+            "inline": true,
+        }));
+
+        let result = send_with_retry("Discord Webhook", retry_policy, || {
+            client
+                .post(webhook_url)
+                .header("Content-Type", "application/json")
+                .json(&serde_json::json!({
+                    "embeds": [
+                        {
+                            "title": format!("New Minecraft {}: {}", event.stream, event.id),
+                            "timestamp": event.release_time,
+                            "color": 16776960,
+                            "fields": fields,
+                            "footer": {
+                                "text": config.discord.username,
+                                "icon_url": config.discord.icon
+                            }
+                        }
+                    ]
+                }))
+        });
+        match result {
+            Ok(response) => println!(
+                "Posted to Discord Webhook, received code {}",
+                response.status().as_str(),
+            ),
+            Err(err) => println!("Giving up on posting to Discord Webhook this cycle: {}", err),
+        }
+    }
+
+    if config.telegram.is_enabled() {
+        let bot_token = config
+            .telegram
+            .bot_token
+            .as_ref()
+            .expect("TelegramConfig::is_enabled() only returns true when bot_token is set");
+        let chat_id = config
+            .telegram
+            .chat_id
+            .as_ref()
+            .expect("TelegramConfig::is_enabled() only returns true when chat_id is set");
+        let send_message_url = format!("{}/bot{}/sendMessage", TELEGRAM_API_URL, bot_token);
+        let text = format!(
+            "*New Minecraft {}:* {}\n{} {}\n{}",
+            escape_markdown_v2(&event.stream),
+            escape_markdown_v2(&event.id),
+            escape_markdown_v2(&event.kind),
+            escape_markdown_v2(release_diff_string),
+            escape_markdown_v2(&event.url),
+        );
+        let result = send_with_retry("Telegram", retry_policy, || {
+            client.post(&send_message_url).json(&serde_json::json!({
+                "chat_id": chat_id,
+                "text": text,
+                "parse_mode": "MarkdownV2",
+                "disable_web_page_preview": false,
+            }))
+        });
+        match result {
+            Ok(response) => println!(
+                "Posted to Telegram, received code {}",
+                response.status().as_str(),
+            ),
+            Err(err) => println!("Giving up on posting to Telegram this cycle: {}", err),
+        }
+    }
+}
+
+/// Formats a byte count as a human-readable size, e.g. `49.3 MB`.
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: &[&str] = &["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+/// Escapes the characters Telegram's MarkdownV2 parser treats as special,
+/// see https://core.telegram.org/bots/api#markdownv2-style
+fn escape_markdown_v2(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if "_*[]()~`>#+-=|{}.!".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
 }
 
 fn main() {
-    let discord_webhook_url = std::env::var("DISCORD_WEBHOOK_URL")
-        .expect("DISCORD_WEBHOOK_URL not set");
-    let healthchecks_url = std::env::var("HEALTHCHECKS_URL").ok();
+    let config = Config::load();
+    config.log_active_sinks();
+    let retry_policy = RetryPolicy::from_env();
+    let update_interval = Duration::from_secs(config.update_interval_secs);
+    let sources: Vec<Box<dyn VersionSource>> = vec![Box::new(MojangSource)];
 
     let scheduler = thread::spawn(move || {
         let client = &Client::new();
         loop {
-            check_minecraft_update(client, &discord_webhook_url, &healthchecks_url);
-            thread::sleep(UPDATE_INTERVAL);
+            poll_sources(client, &config, &retry_policy, &sources);
+            thread::sleep(update_interval);
         }
     });
     scheduler.join().unwrap();